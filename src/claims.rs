@@ -0,0 +1,242 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Outcome of checking a single `NumericDate` claim (`exp`, `nbf`, `iat`)
+/// against the current time plus leeway.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TemporalStatus {
+    Valid,
+    Expired,
+    NotYetValid,
+    Missing,
+}
+
+/// A `NumericDate` claim together with the raw value it was checked
+/// against, so the UI can render a message like "exp 42s in the past"
+/// instead of just a status.
+#[derive(Debug, Clone, Copy)]
+pub struct TemporalClaim {
+    pub status: TemporalStatus,
+    /// Seconds since the Unix epoch, if the claim was present
+    pub value: Option<i64>,
+}
+
+/// Outcome of checking the `aud` claim against a caller-supplied set of
+/// acceptable audiences. `aud` may be a single string or an array of
+/// strings per RFC 7519; either is treated as "any-of-these" membership.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AudienceStatus {
+    Matched,
+    NotMatched,
+    Missing,
+}
+
+/// A structured report over the registered RFC 7519 claims, so a caller can
+/// see not just whether the signature is valid but whether the token itself
+/// would be rejected on semantic grounds.
+#[derive(Debug, Clone)]
+pub struct ClaimsReport {
+    pub exp: TemporalClaim,
+    pub nbf: TemporalClaim,
+    pub iat: TemporalClaim,
+    pub aud: AudienceStatus,
+    pub iss: Option<String>,
+    pub sub: Option<String>,
+}
+
+impl ClaimsReport {
+    /// Renders each checked claim as a short, human-readable line, e.g.
+    /// `"exp: expired 42s ago"` or `"aud: does not match any expected audience"`.
+    /// Intended for the UI to list alongside signature status.
+    pub fn summary_lines(&self, now: i64) -> Vec<String> {
+        let mut lines = Vec::new();
+        lines.push(describe_temporal("exp", &self.exp, now));
+        lines.push(describe_temporal("nbf", &self.nbf, now));
+        lines.push(describe_temporal("iat", &self.iat, now));
+        lines.push(match self.aud {
+            AudienceStatus::Matched => "aud: matches an expected audience".to_string(),
+            AudienceStatus::NotMatched => "aud: does not match any expected audience".to_string(),
+            AudienceStatus::Missing => "aud: missing".to_string(),
+        });
+        lines.push(match &self.iss {
+            Some(iss) => format!("iss: {}", iss),
+            _ => "iss: missing".to_string(),
+        });
+        lines.push(match &self.sub {
+            Some(sub) => format!("sub: {}", sub),
+            _ => "sub: missing".to_string(),
+        });
+        lines
+    }
+}
+
+fn describe_temporal(name: &str, claim: &TemporalClaim, now: i64) -> String {
+    match claim.status {
+        TemporalStatus::Missing => format!("{}: missing", name),
+        TemporalStatus::Valid => format!("{}: valid", name),
+        TemporalStatus::Expired => {
+            format!("{}: expired {}s ago", name, now - claim.value.unwrap_or(now))
+        }
+        TemporalStatus::NotYetValid => format!(
+            "{}: not valid for another {}s",
+            name,
+            claim.value.unwrap_or(now) - now
+        ),
+    }
+}
+
+/// Validates the registered RFC 7519 claims in a decoded JWT payload.
+///
+/// `now` and the claim timestamps are all `NumericDate`: seconds since the
+/// Unix epoch, never ISO-8601 strings. `leeway_seconds` is applied
+/// symmetrically to `exp`, `nbf` and `iat` to absorb clock skew between the
+/// issuer and verifier.
+pub fn validate_claims(
+    payload_json: &str,
+    now: i64,
+    leeway_seconds: i64,
+    expected_audience: &HashSet<String>,
+) -> Result<ClaimsReport> {
+    let value: Value =
+        serde_json::from_str(payload_json).map_err(|e| anyhow!("payload is not valid JSON: {}", e))?;
+
+    Ok(ClaimsReport {
+        exp: temporal_claim(&value, "exp", now, leeway_seconds, TemporalKind::Expiry),
+        nbf: temporal_claim(&value, "nbf", now, leeway_seconds, TemporalKind::NotBefore),
+        iat: temporal_claim(&value, "iat", now, leeway_seconds, TemporalKind::IssuedAt),
+        aud: audience_status(&value, expected_audience),
+        iss: value.get("iss").and_then(Value::as_str).map(str::to_string),
+        sub: value.get("sub").and_then(Value::as_str).map(str::to_string),
+    })
+}
+
+enum TemporalKind {
+    Expiry,
+    NotBefore,
+    IssuedAt,
+}
+
+fn numeric_date(value: &Value, claim: &str) -> Option<i64> {
+    value.get(claim)?.as_f64().map(|seconds| seconds.round() as i64)
+}
+
+fn temporal_claim(
+    value: &Value,
+    claim: &str,
+    now: i64,
+    leeway_seconds: i64,
+    kind: TemporalKind,
+) -> TemporalClaim {
+    let ts = match numeric_date(value, claim) {
+        Some(ts) => ts,
+        None => {
+            return TemporalClaim {
+                status: TemporalStatus::Missing,
+                value: None,
+            }
+        }
+    };
+
+    let status = match kind {
+        TemporalKind::Expiry => {
+            if now - leeway_seconds > ts {
+                TemporalStatus::Expired
+            } else {
+                TemporalStatus::Valid
+            }
+        }
+        TemporalKind::NotBefore | TemporalKind::IssuedAt => {
+            if now + leeway_seconds < ts {
+                TemporalStatus::NotYetValid
+            } else {
+                TemporalStatus::Valid
+            }
+        }
+    };
+
+    TemporalClaim {
+        status,
+        value: Some(ts),
+    }
+}
+
+fn audience_status(value: &Value, expected: &HashSet<String>) -> AudienceStatus {
+    let aud = match value.get("aud") {
+        Some(aud) => aud,
+        None => return AudienceStatus::Missing,
+    };
+
+    let candidates: Vec<&str> = match aud {
+        Value::String(s) => vec![s.as_str()],
+        Value::Array(values) => values.iter().filter_map(Value::as_str).collect(),
+        _ => return AudienceStatus::NotMatched,
+    };
+
+    if candidates.iter().any(|candidate| expected.contains(*candidate)) {
+        AudienceStatus::Matched
+    } else {
+        AudienceStatus::NotMatched
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn expired_token() {
+        let payload = r#"{"exp": 1000, "sub": "alice"}"#;
+        let report = validate_claims(payload, 2000, 0, &HashSet::new()).unwrap();
+
+        assert_eq!(report.exp.status, TemporalStatus::Expired);
+        assert_eq!(report.exp.value, Some(1000));
+    }
+
+    #[test]
+    fn exp_within_leeway() {
+        let payload = r#"{"exp": 1000}"#;
+        let report = validate_claims(payload, 1010, 30, &HashSet::new()).unwrap();
+
+        assert_eq!(report.exp.status, TemporalStatus::Valid);
+    }
+
+    #[test]
+    fn not_yet_valid() {
+        let payload = r#"{"nbf": 2000}"#;
+        let report = validate_claims(payload, 1000, 0, &HashSet::new()).unwrap();
+
+        assert_eq!(report.nbf.status, TemporalStatus::NotYetValid);
+    }
+
+    #[test]
+    fn audience_array_any_of_match() {
+        let payload = r#"{"aud": ["service-a", "service-b"]}"#;
+        let mut expected = HashSet::new();
+        expected.insert("service-b".to_string());
+
+        let report = validate_claims(payload, 0, 0, &expected).unwrap();
+
+        assert_eq!(report.aud, AudienceStatus::Matched);
+    }
+
+    #[test]
+    fn audience_string_no_match() {
+        let payload = r#"{"aud": "service-a"}"#;
+        let mut expected = HashSet::new();
+        expected.insert("service-b".to_string());
+
+        let report = validate_claims(payload, 0, 0, &expected).unwrap();
+
+        assert_eq!(report.aud, AudienceStatus::NotMatched);
+    }
+
+    #[test]
+    fn missing_claims() {
+        let report = validate_claims("{}", 0, 0, &HashSet::new()).unwrap();
+
+        assert_eq!(report.exp.status, TemporalStatus::Missing);
+        assert_eq!(report.aud, AudienceStatus::Missing);
+        assert!(report.iss.is_none());
+    }
+}
